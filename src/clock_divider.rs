@@ -0,0 +1,121 @@
+/// Fractional (error-accumulating) clock divider, producing an output clock from an input clock
+/// at an arbitrary rational ratio `freq2 / freq1`.
+pub struct ClockDivider {
+    /// Input ticks consumed per output period, rounded down: `freq1 / freq2`
+    ticks_per_period: u64,
+    /// Remainder left over per output period: `freq1 - ticks_per_period * freq2`
+    remainder: u64,
+    /// Target output rate, used as the accumulator's overflow threshold
+    freq2: u64,
+    /// Error accumulator
+    acc: u64,
+    /// Input ticks consumed toward the current output period
+    count: u64,
+    /// Length of the output period in progress (`ticks_per_period`, or one more to correct drift)
+    current_period: u64,
+    /// Total number of output edges that have fired
+    ticks_elapsed: u64,
+    prev_clk: bool,
+}
+
+impl ClockDivider {
+    /// Creates a new clock divider that derives an output clock at `freq2` from an input clock
+    /// at `freq1`. Panics if either rate is zero. If `freq2` is above `freq1`, the output fires on
+    /// every input edge, i.e. at `freq1`.
+    pub fn new(freq1: u64, freq2: u64) -> Self {
+        assert!(freq1 > 0 && freq2 > 0, "clock rates must be non-zero");
+
+        let mut divider = ClockDivider {
+            ticks_per_period: freq1 / freq2,
+            remainder: freq1 % freq2,
+            freq2,
+            acc: 0,
+            count: 0,
+            current_period: 0,
+            ticks_elapsed: 0,
+            prev_clk: false,
+        };
+        divider.current_period = divider.next_period_len();
+        divider
+    }
+
+    /// Accumulates one output period's worth of error and returns the number of input ticks the
+    /// next period should consume
+    fn next_period_len(&mut self) -> u64 {
+        self.acc += self.remainder;
+        if self.acc >= self.freq2 {
+            self.acc -= self.freq2;
+            self.ticks_per_period + 1
+        } else {
+            self.ticks_per_period
+        }
+        .max(1)
+    }
+
+    /// Advances the divider by one input clock sample. Returns true if an output clock edge
+    /// fired on this call.
+    pub fn tick(&mut self, clk: bool) -> bool {
+        let input_edge = clk && !self.prev_clk;
+        self.prev_clk = clk;
+
+        if !input_edge {
+            return false;
+        }
+
+        self.count += 1;
+        if self.count < self.current_period {
+            return false;
+        }
+
+        self.count = 0;
+        self.ticks_elapsed += 1;
+        self.current_period = self.next_period_len();
+
+        true
+    }
+
+    /// Returns the number of output edges that have fired so far
+    pub fn value(&self) -> u64 {
+        self.ticks_elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_divider_integer_ratio() {
+        // freq1 / freq2 = 4, so every 4th input tick should produce an output edge
+        let mut divider = ClockDivider::new(400, 100);
+
+        let mut edges = 0;
+        for i in 1..=16 {
+            if divider.tick(true) {
+                edges += 1;
+            }
+            divider.tick(false);
+            if i % 4 == 0 {
+                assert_eq!(edges, i / 4);
+            }
+        }
+        assert_eq!(divider.value(), 4);
+    }
+
+    #[test]
+    fn test_clock_divider_fractional_ratio_is_driftless() {
+        // freq1 / freq2 = 3.5, so output edges should alternate consuming 3 and 4 input ticks,
+        // averaging out exactly over time rather than drifting
+        let mut divider = ClockDivider::new(7, 2);
+
+        let mut input_ticks = 0;
+        while divider.value() < 100 {
+            divider.tick(true);
+            divider.tick(false);
+            input_ticks += 1;
+        }
+
+        // Over 100 output periods, exactly 350 input ticks should have been consumed
+        assert_eq!(input_ticks, 350);
+    }
+}
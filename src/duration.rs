@@ -0,0 +1,114 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+#[cfg(not(target_arch = "wasm32"))]
+type Femtos = u128;
+#[cfg(target_arch = "wasm32")]
+type Femtos = u64;
+
+/// Number of femtoseconds in one second
+pub const FEMTOS_PER_SEC: Femtos = 1_000_000_000_000_000;
+/// Number of femtoseconds in one millisecond
+pub const FEMTOS_PER_MILLI: Femtos = 1_000_000_000_000;
+/// Number of femtoseconds in one microsecond
+pub const FEMTOS_PER_MICRO: Femtos = 1_000_000_000;
+/// Number of femtoseconds in one nanosecond
+pub const FEMTOS_PER_NANO: Femtos = 1_000_000;
+
+/// A span of simulated time, stored as a whole number of femtoseconds.
+///
+/// Femtosecond resolution lets a `Scheduler` co-simulate clock domains at very different, and
+/// non-integer-ratio, frequencies with little rounding error: a period given in whole
+/// femtoseconds is kept exactly, while `from_hz` rounds a period to the nearest femtosecond.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Duration(Femtos);
+
+impl Duration {
+    pub const ZERO: Duration = Duration(0);
+
+    pub const fn from_femtos(femtos: Femtos) -> Self {
+        Duration(femtos)
+    }
+
+    pub const fn from_nanos(nanos: Femtos) -> Self {
+        Duration(nanos * FEMTOS_PER_NANO)
+    }
+
+    pub const fn from_micros(micros: Femtos) -> Self {
+        Duration(micros * FEMTOS_PER_MICRO)
+    }
+
+    pub const fn from_millis(millis: Femtos) -> Self {
+        Duration(millis * FEMTOS_PER_MILLI)
+    }
+
+    pub const fn from_secs(secs: Femtos) -> Self {
+        Duration(secs * FEMTOS_PER_SEC)
+    }
+
+    /// Builds the period of a clock running at `hz` cycles per second
+    pub fn from_hz(hz: f64) -> Self {
+        Duration((FEMTOS_PER_SEC as f64 / hz).round() as Femtos)
+    }
+
+    pub const fn as_femtos(&self) -> Femtos {
+        self.0
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+    fn add(self, rhs: Duration) -> Duration {
+        Duration(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+    fn sub(self, rhs: Duration) -> Duration {
+        Duration(self.0 - rhs.0)
+    }
+}
+
+impl Mul<Femtos> for Duration {
+    type Output = Duration;
+    fn mul(self, rhs: Femtos) -> Duration {
+        Duration(self.0 * rhs)
+    }
+}
+
+impl Div<Femtos> for Duration {
+    type Output = Duration;
+    fn div(self, rhs: Femtos) -> Duration {
+        Duration(self.0 / rhs)
+    }
+}
+
+impl Div for Duration {
+    type Output = Femtos;
+    fn div(self, rhs: Duration) -> Femtos {
+        self.0 / rhs.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_arithmetic() {
+        let a = Duration::from_nanos(10);
+        let b = Duration::from_nanos(5);
+
+        assert_eq!(a + b, Duration::from_nanos(15));
+        assert_eq!(a - b, Duration::from_nanos(5));
+        assert_eq!(b * 2, Duration::from_nanos(10));
+        assert_eq!(a / 2, Duration::from_nanos(5));
+        assert_eq!(a / b, 2);
+    }
+
+    #[test]
+    fn test_duration_from_hz() {
+        // A 1 MHz clock has a period of 1000 ns
+        assert_eq!(Duration::from_hz(1_000_000.0), Duration::from_nanos(1000));
+    }
+}
@@ -46,29 +46,63 @@ impl Default for DFlipflop {
     }
 }
 
+/// Resolves the otherwise-undefined S=1, R=1 condition of an [`SRFlipflop`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dominance {
+    /// When S=R=1, Q is forced low.
+    #[default]
+    Reset,
+    /// When S=R=1, Q is forced high.
+    Set,
+}
+
 /// Edge-triggered SR flip-flop
 #[derive(Clone, Copy)]
 pub struct SRFlipflop {
     master: latch::GatedSRLatch,
     slave: latch::GatedSRLatch,
+    dominance: Dominance,
 }
 
 impl SRFlipflop {
-    /// Creates a new gated SR flip-flop in the reset state
+    /// Creates a new gated SR flip-flop in the reset state, with reset dominance on S=R=1
     pub fn new() -> Self {
+        Self::with_dominance(Dominance::default())
+    }
+
+    /// Creates a new gated SR flip-flop in the reset state, using `dominance` to resolve the
+    /// S=R=1 condition
+    pub fn with_dominance(dominance: Dominance) -> Self {
         SRFlipflop {
             master: latch::GatedSRLatch::new(),
             slave: latch::GatedSRLatch::new(),
+            dominance,
         }
     }
 
     /// Updates the flip-flop based on new inputs. The flip-flop triggers on the rising edge of the
     /// clock.
+    ///
+    /// If both `s` and `r` are true, the configured [`Dominance`] decides the resulting value of
+    /// `q` rather than leaving it undefined.
     pub fn update(&mut self, clk: bool, s: bool, r: bool) {
+        let (s, r) = self.resolve_dominance(s, r);
         self.master.set(s, not(&clk), r);
         self.slave.set(self.master.q(), clk, self.master.qn());
     }
 
+    /// Resolves simultaneous S=1, R=1 inputs to a single dominant input, per `self.dominance`
+    fn resolve_dominance(&self, s: bool, r: bool) -> (bool, bool) {
+        if s && r {
+            match self.dominance {
+                Dominance::Reset => (false, true),
+                Dominance::Set => (true, false),
+            }
+        } else {
+            (s, r)
+        }
+    }
+
     pub fn q(&self) -> bool {
         self.slave.q()
     }
@@ -222,6 +256,27 @@ mod tests {
         assert_eq!(flipflop.q(), expect_q);
     }
 
+    #[test]
+    fn test_sr_flipflop_dominance() {
+        // Reset dominance (the default): S=R=1 forces Q low
+        let mut flipflop = SRFlipflop::with_dominance(Dominance::Reset);
+
+        let mut clk = false;
+        flipflop.update(clk, true, true);
+        clk = true;
+        flipflop.update(clk, true, true);
+        assert!(!flipflop.q());
+
+        // Set dominance: S=R=1 forces Q high
+        let mut flipflop = SRFlipflop::with_dominance(Dominance::Set);
+
+        let mut clk = false;
+        flipflop.update(clk, true, true);
+        clk = true;
+        flipflop.update(clk, true, true);
+        assert!(flipflop.q());
+    }
+
     #[test]
     fn test_jk_flipflop() {
         let mut flipflop = JKFlipflop::new();
@@ -0,0 +1,162 @@
+use crate::counter::RippleCounter;
+use crate::gate::{and, not};
+
+/// Retriggerable monostable (one-shot) flip-flop with a `W`-bit pulse width counter.
+///
+/// On a rising edge of `trigger`, `q()` goes high for a fixed number of clock cycles, then
+/// returns low automatically. If `retriggerable` is set, a new trigger edge arriving mid-pulse
+/// restarts the count rather than being ignored.
+pub struct Monostable<const W: usize> {
+    counter: RippleCounter<W>,
+    pulse_width: u64,
+    retriggerable: bool,
+    active: bool,
+    prev_clk: bool,
+    prev_trigger: bool,
+}
+
+impl<const W: usize> Monostable<W> {
+    /// Creates a new monostable that drives `q()` high for `pulse_width` clock cycles per
+    /// trigger edge. Panics if `pulse_width` doesn't fit in the `W`-bit internal counter.
+    pub fn new(pulse_width: u64, retriggerable: bool) -> Self {
+        if let Some(capacity) = 1u64.checked_shl(W as u32) {
+            assert!(
+                pulse_width < capacity,
+                "pulse_width must fit within the W-bit internal counter"
+            );
+        }
+
+        Monostable {
+            counter: RippleCounter::new(),
+            pulse_width,
+            retriggerable,
+            active: false,
+            prev_clk: false,
+            prev_trigger: false,
+        }
+    }
+
+    /// Updates the monostable. `clk` advances the pulse countdown on its rising edge; `trigger`
+    /// starts (or, if `retriggerable`, restarts) the pulse on its own rising edge.
+    pub fn update(&mut self, clk: bool, trigger: bool) {
+        let trigger_edge = and(&[trigger, not(&self.prev_trigger)]);
+        self.prev_trigger = trigger;
+
+        let mut just_retriggered = false;
+        if trigger_edge && (!self.active || self.retriggerable) {
+            self.counter.clear();
+            self.active = true;
+            just_retriggered = true;
+        }
+
+        let clk_edge = and(&[clk, not(&self.prev_clk)]);
+        self.prev_clk = clk;
+
+        // A clock edge landing in the same call as a (re)trigger must not also advance the
+        // counter, or the restarted pulse would be one cycle short
+        if self.active && clk_edge && !just_retriggered {
+            self.counter.update(true);
+            self.counter.update(false);
+            let elapsed: u64 = self.counter.value().unwrap_or(u64::MAX);
+            if elapsed >= self.pulse_width {
+                self.active = false;
+            }
+        }
+    }
+
+    /// Returns true while the pulse is active
+    pub fn q(&self) -> bool {
+        self.active
+    }
+
+    pub fn qn(&self) -> bool {
+        !self.active
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monostable_pulse() {
+        let mut monostable = Monostable::<8>::new(3, false);
+        assert!(!monostable.q());
+
+        // Trigger the pulse
+        monostable.update(false, true);
+        assert!(monostable.q());
+
+        // Pulse stays high for `pulse_width` clock cycles, then drops
+        for _ in 0..3 {
+            monostable.update(true, false);
+            monostable.update(false, false);
+        }
+        assert!(!monostable.q());
+    }
+
+    #[test]
+    fn test_monostable_retrigger() {
+        let mut monostable = Monostable::<8>::new(3, true);
+
+        monostable.update(false, true);
+        monostable.update(true, false);
+        monostable.update(false, false);
+
+        // Retrigger mid-pulse: the count should restart rather than expire early
+        monostable.update(false, true);
+        monostable.update(true, false);
+        monostable.update(false, false);
+        monostable.update(true, false);
+        monostable.update(false, false);
+        assert!(monostable.q());
+
+        monostable.update(true, false);
+        assert!(!monostable.q());
+    }
+
+    #[test]
+    fn test_monostable_retrigger_on_same_call_as_clk_edge_still_gets_full_pulse() {
+        let mut monostable = Monostable::<8>::new(3, true);
+
+        monostable.update(false, true);
+        monostable.update(true, false);
+        monostable.update(false, false);
+
+        // Retrigger and a clock rising edge land in the same call
+        monostable.update(true, true);
+
+        // The restarted pulse should still run for the full 3 cycles from here
+        monostable.update(false, false);
+        monostable.update(true, false);
+        monostable.update(false, false);
+        monostable.update(true, false);
+        assert!(monostable.q());
+
+        monostable.update(false, false);
+        monostable.update(true, false);
+        assert!(!monostable.q());
+    }
+
+    #[test]
+    #[should_panic(expected = "pulse_width must fit within the W-bit internal counter")]
+    fn test_monostable_rejects_pulse_width_too_wide_for_counter() {
+        Monostable::<4>::new(20, false);
+    }
+
+    #[test]
+    fn test_monostable_non_retriggerable_ignores_mid_pulse_trigger() {
+        let mut monostable = Monostable::<8>::new(3, false);
+
+        monostable.update(false, true);
+        monostable.update(true, false);
+        monostable.update(false, false);
+
+        // A second trigger edge mid-pulse is ignored; the pulse still expires on schedule
+        monostable.update(false, true);
+        monostable.update(true, false);
+        monostable.update(false, false);
+        monostable.update(true, false);
+        assert!(!monostable.q());
+    }
+}
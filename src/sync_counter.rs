@@ -0,0 +1,154 @@
+use crate::flipflop::JKFlipflop;
+use crate::gate::{and, not};
+
+/// Counting direction for a [`SyncCounter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+/// Synchronous counter of `N` bits in width, driven from a single shared clock with
+/// combinational carry/borrow logic between stages, plus a parallel `load`.
+pub struct SyncCounter<const N: usize> {
+    flipflops: [JKFlipflop; N],
+    direction: Direction,
+    pending_load: Option<u64>,
+}
+
+impl<const N: usize> SyncCounter<N> {
+    /// Creates a new counter in the given direction, reset to zero
+    pub fn new(direction: Direction) -> Self {
+        let mut counter = SyncCounter {
+            flipflops: core::array::from_fn(|_| JKFlipflop::new()),
+            direction,
+            pending_load: None,
+        };
+
+        counter.init();
+        counter
+    }
+
+    fn init(&mut self) {
+        // Hold every stage with the clock low, to avoid the race condition that occurs when
+        // setting J/K and CLK high simultaneously
+        for flipflop in self.flipflops.iter_mut() {
+            flipflop.update(false, false, false);
+        }
+    }
+
+    /// Schedules `value` to be loaded into every stage in parallel.
+    ///
+    /// Like setting D on a [`crate::flipflop::DFlipflop`], the value is captured by the next call
+    /// to `update`, which must have `clk` low, and committed on the following rising edge. If that
+    /// next call has `clk` high, the load is dropped.
+    pub fn load(&mut self, value: u64) {
+        self.pending_load = Some(value);
+    }
+
+    /// Updates the counter. The counter triggers on the rising edge of `clk`, like the underlying
+    /// flip-flops.
+    pub fn update(&mut self, clk: bool) {
+        if let Some(value) = self.pending_load.take() {
+            for (i, flipflop) in self.flipflops.iter_mut().enumerate() {
+                let bit = (value >> i) & 1 == 1;
+                flipflop.update(clk, bit, not(&bit));
+            }
+            return;
+        }
+
+        // Stage `i` toggles only when every lower stage is asserted (counting up) or deasserted
+        // (counting down), forming a synchronous carry/borrow chain
+        let mut enable = true;
+        for flipflop in self.flipflops.iter_mut() {
+            let carry_in = match self.direction {
+                Direction::Up => flipflop.q(),
+                Direction::Down => flipflop.qn(),
+            };
+
+            flipflop.update(clk, enable, enable);
+            enable = and(&[enable, carry_in]);
+        }
+    }
+
+    /// Schedules the counter to be reset to zero; see [`SyncCounter::load`]
+    pub fn clear(&mut self) {
+        self.load(0);
+    }
+
+    /// Get the value of the counter
+    pub fn value<T: TryFrom<u64>>(&self) -> Result<T, T::Error> {
+        let mut val = 0u64;
+        for (i, ff) in self.flipflops.iter().enumerate() {
+            val |= if ff.q() { 1 << i } else { 0 };
+        }
+
+        T::try_from(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_counter_up() {
+        const WIDTH: usize = 4;
+        let mut counter = SyncCounter::<WIDTH>::new(Direction::Up);
+        assert_eq!(counter.value::<u64>().unwrap(), 0);
+
+        for expected in 1..16 {
+            counter.update(false);
+            counter.update(true);
+            assert_eq!(counter.value::<u64>().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_sync_counter_down() {
+        const WIDTH: usize = 4;
+        let mut counter = SyncCounter::<WIDTH>::new(Direction::Down);
+        assert_eq!(counter.value::<u64>().unwrap(), 0);
+
+        // Counting down from zero borrows through every stage and wraps to all-ones
+        counter.update(false);
+        counter.update(true);
+        assert_eq!(counter.value::<u64>().unwrap(), 15);
+
+        counter.update(false);
+        counter.update(true);
+        assert_eq!(counter.value::<u64>().unwrap(), 14);
+    }
+
+    #[test]
+    fn test_sync_counter_parallel_load() {
+        const WIDTH: usize = 8;
+        let mut counter = SyncCounter::<WIDTH>::new(Direction::Up);
+
+        counter.load(42);
+        counter.update(false);
+        counter.update(true);
+        assert_eq!(counter.value::<u64>().unwrap(), 42);
+
+        // Counting resumes from the loaded value
+        counter.update(false);
+        counter.update(true);
+        assert_eq!(counter.value::<u64>().unwrap(), 43);
+    }
+
+    #[test]
+    fn test_sync_counter_clear() {
+        const WIDTH: usize = 8;
+        let mut counter = SyncCounter::<WIDTH>::new(Direction::Up);
+
+        counter.load(200);
+        counter.update(false);
+        counter.update(true);
+        assert_eq!(counter.value::<u64>().unwrap(), 200);
+
+        counter.clear();
+        counter.update(false);
+        counter.update(true);
+        assert_eq!(counter.value::<u64>().unwrap(), 0);
+    }
+}